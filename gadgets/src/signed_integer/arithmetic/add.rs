@@ -1,3 +1,4 @@
+use super::multieq::MultiEq;
 use crate::{errors::IntegerError, Int, Int128, Int16, Int32, Int64, Int8};
 use snarkos_models::{
     curves::{fp_parameters::FpParameters, PrimeField},
@@ -11,18 +12,20 @@ use snarkos_models::{
 };
 
 /// Modular addition for a signed integer gadget
+///
+/// See [`MultiEq`] for why this takes a `MultiEq` instead of a bare `ConstraintSystem`.
 pub trait Add<Rhs = Self>
 where
     Self: std::marker::Sized,
 {
     #[must_use]
-    fn add<F: PrimeField, CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, IntegerError>;
+    fn add<F: PrimeField, CS: ConstraintSystem<F>>(&self, cs: &mut MultiEq<F, CS>, other: &Self) -> Result<Self, IntegerError>;
 }
 
 macro_rules! add_int_impl {
     ($($gadget: ident)*) => ($(
         impl Add for $gadget {
-            fn add<F: PrimeField, CS: ConstraintSystem<F>>(&self, mut cs: CS, other: &Self) -> Result<Self, IntegerError> {
+            fn add<F: PrimeField, CS: ConstraintSystem<F>>(&self, cs: &mut MultiEq<F, CS>, other: &Self) -> Result<Self, IntegerError> {
                 // Make some arbitrary bounds for ourselves to avoid overflows
                 // in the scalar field
                 assert!(F::Params::MODULUS_BITS >= 128);
@@ -116,6 +119,12 @@ macro_rules! add_int_impl {
                 // Storage area for the resulting bits
                 let mut result_bits = vec![];
 
+                // This linear combination tracks the result bits on the
+                // opposite side of the balance equation from `lc`, so the
+                // equality can be routed through `MultiEq` instead of a
+                // single "lc == 0" constraint
+                let mut rhs = LinearCombination::zero();
+
                 // Allocate each bit_gadget of the result
                 let mut coeff = F::one();
                 let mut i = 0;
@@ -125,9 +134,8 @@ macro_rules! add_int_impl {
                         result_value.map(|v| (v >> i) & 1 == 1).get()
                     })?;
 
-                    // Subtract this bit_gadget from the linear combination to ensure that the sums
-                    // balance out
-                    lc = lc - (coeff, b.get_variable());
+                    // Add this bit_gadget to the other side of the balance equation
+                    rhs = rhs + (coeff, b.get_variable());
 
                     result_bits.push(b.into());
 
@@ -136,8 +144,10 @@ macro_rules! add_int_impl {
                     coeff.double_in_place();
                 }
 
-                // Enforce that the linear combination equals zero
-                cs.enforce(|| "modular addition", |lc| lc, |lc| lc, |_| lc);
+                // Enforce that the two sides of the balance equation are equal,
+                // batching the constraint with any other additions sharing this
+                // `MultiEq` instead of spending a full field-level constraint
+                cs.enforce_equal(i, &lc, &rhs);
 
                 // Discard carry bits we don't care about
                 result_bits.truncate(<$gadget as Int>::SIZE);
@@ -0,0 +1,213 @@
+use super::multieq::MultiEq;
+use crate::{errors::IntegerError, Int, Int128, Int16, Int32, Int64, Int8};
+use snarkos_models::{
+    curves::{fp_parameters::FpParameters, PrimeField},
+    gadgets::{
+        r1cs::{Assignment, ConstraintSystem, LinearCombination},
+        utilities::{
+            alloc::AllocGadget,
+            boolean::{AllocatedBit, Boolean},
+        },
+    },
+};
+
+/// Sums many signed integer gadgets of the same width in a single balance,
+/// matching the `addmany` pattern used by hash-function circuits that fold
+/// several words together in one constraint instead of one per pair.
+///
+/// See [`MultiEq`] for why this takes a `MultiEq` instead of a bare `ConstraintSystem`.
+pub trait AddMany<Rhs = Self>
+where
+    Self: std::marker::Sized,
+{
+    #[must_use]
+    fn add_many<F: PrimeField, CS: ConstraintSystem<F>>(cs: &mut MultiEq<F, CS>, operands: &[Self]) -> Result<Self, IntegerError>;
+}
+
+macro_rules! add_many_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl AddMany for $gadget {
+            fn add_many<F: PrimeField, CS: ConstraintSystem<F>>(cs: &mut MultiEq<F, CS>, operands: &[Self]) -> Result<Self, IntegerError> {
+                // Make some arbitrary bounds for ourselves to avoid overflows
+                // in the scalar field
+                assert!(F::Params::MODULUS_BITS >= 128);
+
+                if operands.is_empty() {
+                    return Err(IntegerError::InvalidArgument(
+                        "add_many requires at least one operand".to_string(),
+                    ));
+                }
+
+                // Each operand's bits contribute its raw (unsigned) `SIZE`-bit
+                // pattern to `lc`, e.g. `-1i8` contributes `255`, not `-1`, so
+                // bound the sum by the true unsigned range per operand rather
+                // than the signed `IntegerType::max_value()`.
+                let raw_mask = 2i128.pow(<$gadget as Int>::SIZE as u32) - 1i128;
+                let mut max_value = operands.len() as i128 * raw_mask;
+
+                // Keep track of the resulting value, accumulated from each
+                // operand's raw bit pattern to match what `lc` computes
+                let mut result_value = Some(0i128);
+
+                // This is a linear combination that we will enforce to equal the result bits
+                let mut lc = LinearCombination::zero();
+
+                let mut all_constants = true;
+
+                // Iterate over each operand, folding its bits into the same
+                // linear combination so the whole sum balances in one shot
+                for operand in operands {
+                    // Accumulate the value
+                    match operand.value {
+                        Some(val) => {
+                            result_value.as_mut().map(|v| *v += i128::from(val) & raw_mask);
+                        }
+                        None => {
+                            // If any of the operands have unknown value, we won't
+                            // know the value of the result
+                            result_value = None;
+                        }
+                    }
+
+                    // Iterate over each bit_gadget of the operand and add each bit to
+                    // the linear combination
+                    let mut coeff = F::one();
+                    for bit in &operand.bits {
+                        match *bit {
+                            Boolean::Is(ref bit) => {
+                                all_constants = false;
+
+                                // Add the coeff * bit_gadget
+                                lc = lc + (coeff, bit.get_variable());
+                            }
+                            Boolean::Not(ref bit) => {
+                                all_constants = false;
+
+                                // Add coeff * (1 - bit_gadget) = coeff * ONE - coeff * bit_gadget
+                                lc = lc + (coeff, CS::one()) - (coeff, bit.get_variable());
+                            }
+                            Boolean::Constant(bit) => {
+                                if bit {
+                                    lc = lc + (coeff, CS::one());
+                                }
+                            }
+                        }
+
+                        coeff.double_in_place();
+                    }
+                }
+
+                // The value of the actual result is modulo 2 ^ $size
+                let modular_value = result_value.map(|v| v as <$gadget as Int>::IntegerType);
+
+                if all_constants && modular_value.is_some() {
+                    // We can just return a constant, rather than
+                    // unpacking the result into allocated bits.
+
+                    return Ok(Self::constant(modular_value.unwrap()));
+                }
+
+                // Storage area for the resulting bits
+                let mut result_bits = vec![];
+
+                // This linear combination tracks the result bits on the
+                // opposite side of the balance equation from `lc`, so the
+                // equality can be routed through `MultiEq` instead of a
+                // single "lc == 0" constraint
+                let mut rhs = LinearCombination::zero();
+
+                // Allocate each bit_gadget of the result
+                let mut coeff = F::one();
+                let mut i = 0;
+                while max_value != 0 {
+                    // Allocate the bit_gadget
+                    let b = AllocatedBit::alloc(cs.ns(|| format!("result bit_gadget {}", i)), || {
+                        result_value.map(|v| (v >> i) & 1 == 1).get()
+                    })?;
+
+                    // Add this bit_gadget to the other side of the balance equation
+                    rhs = rhs + (coeff, b.get_variable());
+
+                    result_bits.push(b.into());
+
+                    max_value >>= 1;
+                    i += 1;
+                    coeff.double_in_place();
+                }
+
+                // Enforce that the two sides of the balance equation are equal,
+                // batching the constraint with any other additions sharing this
+                // `MultiEq` instead of spending a full field-level constraint
+                cs.enforce_equal(i, &lc, &rhs);
+
+                // Discard carry bits we don't care about
+                result_bits.truncate(<$gadget as Int>::SIZE);
+
+                Ok(Self {
+                    bits: result_bits,
+                    value: modular_value,
+                })
+            }
+        }
+    )*)
+}
+
+add_many_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_curves::edwards_bls12::Fq;
+    use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+
+    #[test]
+    fn add_many_satisfies_constraints() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(5i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(3i8)).unwrap();
+        let c = Int8::alloc(cs.ns(|| "c"), || Ok(10i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let result = Int8::add_many(&mut multi_eq, &[a, b, c]).unwrap();
+        drop(multi_eq);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(result.value, Some(18i8));
+    }
+
+    #[test]
+    fn add_many_wraps_modulo_size() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(100i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(100i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let result = Int8::add_many(&mut multi_eq, &[a, b]).unwrap();
+        drop(multi_eq);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(result.value, Some(200i16 as i8));
+    }
+
+    #[test]
+    fn add_many_satisfies_constraints_for_negative_operands() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(-1i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(-1i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let result = Int8::add_many(&mut multi_eq, &[a, b]).unwrap();
+        drop(multi_eq);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(result.value, Some(-2i8));
+    }
+
+    #[test]
+    fn add_many_rejects_empty_operands() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let mut multi_eq = MultiEq::new(&mut cs);
+
+        assert!(Int8::add_many(&mut multi_eq, &[]).is_err());
+    }
+}
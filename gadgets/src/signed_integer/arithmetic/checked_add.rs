@@ -0,0 +1,256 @@
+use super::multieq::MultiEq;
+use crate::{errors::IntegerError, Int, Int128, Int16, Int32, Int64, Int8};
+use snarkos_models::{
+    curves::{fp_parameters::FpParameters, PrimeField},
+    gadgets::{
+        r1cs::{Assignment, ConstraintSystem, LinearCombination},
+        utilities::{
+            alloc::AllocGadget,
+            boolean::{AllocatedBit, Boolean},
+        },
+    },
+};
+
+/// Addition that rejects overflow instead of silently wrapping modulo `2^SIZE`.
+///
+/// See [`MultiEq`] for why this takes a `MultiEq` instead of a bare `ConstraintSystem`.
+pub trait CheckedAdd<Rhs = Self>
+where
+    Self: std::marker::Sized,
+{
+    #[must_use]
+    fn checked_add<F: PrimeField, CS: ConstraintSystem<F>>(&self, cs: &mut MultiEq<F, CS>, other: &Self) -> Result<Self, IntegerError>;
+}
+
+macro_rules! checked_add_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl CheckedAdd for $gadget {
+            fn checked_add<F: PrimeField, CS: ConstraintSystem<F>>(&self, cs: &mut MultiEq<F, CS>, other: &Self) -> Result<Self, IntegerError> {
+                // Make some arbitrary bounds for ourselves to avoid overflows
+                // in the scalar field
+                assert!(F::Params::MODULUS_BITS >= 128);
+
+                // Allocate one guard bit beyond `SIZE`: the sum of the two
+                // operands' raw (unsigned) `SIZE`-bit patterns always fits in
+                // `SIZE + 1` bits, so `2 ^ SIZE` is genuine carry headroom,
+                // unlike the `SIZE`-bit bound `2 * IntegerType::max_value()`
+                // that `add` uses for its modular result (which never needs
+                // more than `SIZE` bits and so never leaves a carry bit to check).
+                let mut max_value = 2i128.pow(<$gadget as Int>::SIZE as u32);
+
+                // Keep track of the resulting value
+                let mut result_value = self.value.clone().map(|v| i128::from(v));
+
+                // This is a linear combination that we will enforce to equal the result bits
+                let mut lc = LinearCombination::zero();
+
+                let mut all_constants = true;
+
+                // Accumulate the value
+                match other.value {
+                    Some(val) => {
+                        result_value.as_mut().map(|v| *v += i128::from(val));
+                    }
+                    None => {
+                        // If any of the operands have unknown value, we won't
+                        // know the value of the result
+                        result_value = None;
+                    }
+                }
+
+                // Reject out-of-range sums at witness time rather than letting
+                // them wrap, so the prover learns about the overflow up front
+                if let Some(v) = result_value {
+                    if v < i128::from(<$gadget as Int>::IntegerType::min_value())
+                        || v > i128::from(<$gadget as Int>::IntegerType::max_value())
+                    {
+                        return Err(IntegerError::Overflow(format!(
+                            "overflow on checked addition of {:?} and {:?}",
+                            self.value, other.value
+                        )));
+                    }
+                }
+
+                // Each operand's bits contribute its raw (unsigned) `SIZE`-bit
+                // pattern to `lc`, e.g. `-1i8` contributes `255`, so the result
+                // bits are witnessed from the sum of those raw patterns
+                let raw_mask = 2i128.pow(<$gadget as Int>::SIZE as u32) - 1i128;
+                let raw_value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => Some((i128::from(a) & raw_mask) + (i128::from(b) & raw_mask)),
+                    _ => None,
+                };
+
+                // Iterate over each bit_gadget of self and add each bit to
+                // the linear combination
+                let mut coeff = F::one();
+                for bit in &self.bits {
+                    match *bit {
+                        Boolean::Is(ref bit) => {
+                            all_constants = false;
+                            lc = lc + (coeff, bit.get_variable());
+                        }
+                        Boolean::Not(ref bit) => {
+                            all_constants = false;
+                            lc = lc + (coeff, CS::one()) - (coeff, bit.get_variable());
+                        }
+                        Boolean::Constant(bit) => {
+                            if bit {
+                                lc = lc + (coeff, CS::one());
+                            }
+                        }
+                    }
+
+                    coeff.double_in_place();
+                }
+
+                // Iterate over each bit_gadget of other and add each bit to
+                // the linear combination
+                let mut coeff = F::one();
+                for bit in &other.bits {
+                    match *bit {
+                        Boolean::Is(ref bit) => {
+                            all_constants = false;
+                            lc = lc + (coeff, bit.get_variable());
+                        }
+                        Boolean::Not(ref bit) => {
+                            all_constants = false;
+                            lc = lc + (coeff, CS::one()) - (coeff, bit.get_variable());
+                        }
+                        Boolean::Constant(bit) => {
+                            if bit {
+                                lc = lc + (coeff, CS::one());
+                            }
+                        }
+                    }
+
+                    coeff.double_in_place();
+                }
+
+                // The value of the actual result is modulo 2 ^ $size; we've
+                // already rejected witnesses where this truncates anything
+                let modular_value = result_value.map(|v| v as <$gadget as Int>::IntegerType);
+
+                if all_constants && modular_value.is_some() {
+                    // We can just return a constant, rather than
+                    // unpacking the result into allocated bits.
+
+                    return Ok(Self::constant(modular_value.unwrap()));
+                }
+
+                // Storage area for the resulting, carry-extended bits
+                let mut result_bits = vec![];
+
+                // The other side of the balance equation, routed through `MultiEq`
+                let mut rhs = LinearCombination::zero();
+
+                // Allocate each bit_gadget of the result, including the carry
+                // bits above `SIZE` that a modular `add` would simply discard
+                let mut coeff = F::one();
+                let mut i = 0;
+                while max_value != 0 {
+                    let b = AllocatedBit::alloc(cs.ns(|| format!("result bit_gadget {}", i)), || {
+                        raw_value.map(|v| (v >> i) & 1 == 1).get()
+                    })?;
+
+                    rhs = rhs + (coeff, b.get_variable());
+
+                    result_bits.push(Boolean::from(b));
+
+                    max_value >>= 1;
+                    i += 1;
+                    coeff.double_in_place();
+                }
+
+                // Enforce that the two sides of the balance equation are equal,
+                // batching the constraint with any other additions sharing this
+                // `MultiEq` instead of spending a full field-level constraint
+                cs.enforce_equal(i, &lc, &rhs);
+
+                // Two's-complement signed overflow occurred if and only if the
+                // carry into the sign position differs from the carry out of
+                // it (the guard bit). The carry in is the result's sign bit
+                // XOR both operands' sign bits: enforce it matches the guard
+                // bit so an overflowing witness makes the constraint system
+                // unsatisfiable instead of wrapping. (A blanket "every bit
+                // above `SIZE - 1` is `false`" check is wrong here: adding two
+                // valid negative values always carries out of the sign position.)
+                let operand_signs = Boolean::xor(
+                    cs.ns(|| "xor of operand sign bits"),
+                    &self.bits[<$gadget as Int>::SIZE - 1],
+                    &other.bits[<$gadget as Int>::SIZE - 1],
+                )?;
+                let carry_in = Boolean::xor(
+                    cs.ns(|| "carry into sign bit"),
+                    &operand_signs,
+                    &result_bits[<$gadget as Int>::SIZE - 1],
+                )?;
+                Boolean::enforce_equal(cs.ns(|| "carry in matches guard bit"), &carry_in, &result_bits[<$gadget as Int>::SIZE])?;
+
+                // Discard the guard bit now that it's proven consistent
+                result_bits.truncate(<$gadget as Int>::SIZE);
+
+                Ok(Self {
+                    bits: result_bits,
+                    value: modular_value,
+                })
+            }
+        }
+    )*)
+}
+
+checked_add_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_curves::edwards_bls12::Fq;
+    use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+
+    #[test]
+    fn checked_add_satisfies_constraints_without_overflow() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(100i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(27i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let c = a.checked_add(&mut multi_eq, &b).unwrap();
+        drop(multi_eq);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(c.value, Some(127i8));
+    }
+
+    #[test]
+    fn checked_add_satisfies_constraints_for_negative_operands() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(-100i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(-28i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let c = a.checked_add(&mut multi_eq, &b).unwrap();
+        drop(multi_eq);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(c.value, Some(-128i8));
+    }
+
+    #[test]
+    fn checked_add_rejects_positive_overflow() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(100i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(100i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        assert!(a.checked_add(&mut multi_eq, &b).is_err());
+    }
+
+    #[test]
+    fn checked_add_rejects_negative_overflow() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(-100i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(-100i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        assert!(a.checked_add(&mut multi_eq, &b).is_err());
+    }
+}
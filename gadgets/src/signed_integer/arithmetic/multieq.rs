@@ -0,0 +1,180 @@
+use snarkos_models::{
+    curves::{fp_parameters::FpParameters, PrimeField},
+    gadgets::r1cs::{ConstraintSystem, LinearCombination, SynthesisError, Variable},
+};
+
+/// Batches a sequence of small balance equations (such as the modular
+/// addition/subtraction constraints in this module) into as few `cs.enforce`
+/// calls as the scalar field's capacity allows.
+///
+/// Each call to [`MultiEq::enforce_equal`] packs its `lhs == rhs` equation
+/// into an unused slice of bits of a running accumulator, scaled by
+/// `2^bit_offset`, rather than spending a full field-level constraint on an
+/// equation that only needs a handful of bits. The accumulator is flushed,
+/// via a real `cs.enforce`, once another equation would no longer fit, and
+/// any remainder is flushed on drop.
+///
+/// `Add`, `Sub`, `AddMany`, and `CheckedAdd` all take a `&mut MultiEq`
+/// rather than a bare `ConstraintSystem` for this reason: a caller
+/// performing a sequence of additions/subtractions can share one instance
+/// across all of them and let their balance equations pack together.
+pub struct MultiEq<F: PrimeField, CS: ConstraintSystem<F>> {
+    cs: CS,
+    ops: usize,
+    bit_offset: usize,
+    lhs: LinearCombination<F>,
+    rhs: LinearCombination<F>,
+}
+
+impl<F: PrimeField, CS: ConstraintSystem<F>> MultiEq<F, CS> {
+    pub fn new(cs: CS) -> Self {
+        MultiEq {
+            cs,
+            ops: 0,
+            bit_offset: 0,
+            lhs: LinearCombination::zero(),
+            rhs: LinearCombination::zero(),
+        }
+    }
+
+    fn accumulate(&mut self) {
+        let ops = self.ops;
+        let lhs = self.lhs.clone();
+        let rhs = self.rhs.clone();
+
+        self.cs.enforce(
+            || format!("multieq {}", ops),
+            |_| lhs.clone(),
+            |lc| lc + CS::one(),
+            |_| rhs.clone(),
+        );
+
+        self.lhs = LinearCombination::zero();
+        self.rhs = LinearCombination::zero();
+        self.bit_offset = 0;
+        self.ops += 1;
+    }
+
+    /// Folds the equation `lhs == rhs`, known to span `num_bits` bits, into
+    /// the running accumulator, flushing first if it no longer fits within
+    /// the field's capacity.
+    pub fn enforce_equal(&mut self, num_bits: usize, lhs: &LinearCombination<F>, rhs: &LinearCombination<F>) {
+        if self.bit_offset + num_bits > F::Params::CAPACITY as usize {
+            self.accumulate();
+        }
+
+        let coeff = F::from(2u64).pow(&[self.bit_offset as u64]);
+        self.lhs = self.lhs.clone() + (coeff, lhs);
+        self.rhs = self.rhs.clone() + (coeff, rhs);
+        self.bit_offset += num_bits;
+    }
+}
+
+impl<F: PrimeField, CS: ConstraintSystem<F>> Drop for MultiEq<F, CS> {
+    fn drop(&mut self) {
+        if self.bit_offset > 0 {
+            self.accumulate();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signed_integer::arithmetic::sub::Sub;
+    use crate::Int8;
+    use snarkos_curves::edwards_bls12::Fq;
+    use snarkos_models::gadgets::{r1cs::TestConstraintSystem, utilities::alloc::AllocGadget};
+
+    #[test]
+    fn sharing_a_multieq_across_calls_batches_constraints() {
+        let mut unbatched_cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(unbatched_cs.ns(|| "a"), || Ok(5i8)).unwrap();
+        let b = Int8::alloc(unbatched_cs.ns(|| "b"), || Ok(3i8)).unwrap();
+        let c = Int8::alloc(unbatched_cs.ns(|| "c"), || Ok(10i8)).unwrap();
+
+        {
+            let mut multi_eq = MultiEq::new(&mut unbatched_cs);
+            a.sub(&mut multi_eq, &b).unwrap();
+            drop(multi_eq);
+        }
+        {
+            let mut multi_eq = MultiEq::new(&mut unbatched_cs);
+            a.sub(&mut multi_eq, &c).unwrap();
+            drop(multi_eq);
+        }
+        let unbatched_constraints = unbatched_cs.num_constraints();
+
+        let mut batched_cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(batched_cs.ns(|| "a"), || Ok(5i8)).unwrap();
+        let b = Int8::alloc(batched_cs.ns(|| "b"), || Ok(3i8)).unwrap();
+        let c = Int8::alloc(batched_cs.ns(|| "c"), || Ok(10i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut batched_cs);
+        let first = a.sub(&mut multi_eq, &b).unwrap();
+        let second = a.sub(&mut multi_eq, &c).unwrap();
+        drop(multi_eq);
+
+        assert!(batched_cs.is_satisfied());
+        assert_eq!(first.value, Some(2i8));
+        assert_eq!(second.value, Some(-5i8));
+        assert!(batched_cs.num_constraints() < unbatched_constraints);
+    }
+}
+
+impl<F: PrimeField, CS: ConstraintSystem<F>> ConstraintSystem<F> for MultiEq<F, CS> {
+    type Root = Self;
+
+    fn one() -> Variable {
+        CS::one()
+    }
+
+    fn alloc<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.alloc(annotation, f)
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.alloc_input(annotation, f)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        self.cs.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn)
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.cs.num_constraints()
+    }
+}
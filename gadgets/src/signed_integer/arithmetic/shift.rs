@@ -0,0 +1,135 @@
+use crate::{Int, Int128, Int16, Int32, Int64, Int8};
+use snarkos_models::gadgets::utilities::boolean::Boolean;
+
+/// Constant-amount bitwise shifts and rotations for a signed integer gadget.
+///
+/// Each of these operations only reindexes the existing `Boolean`s of the
+/// operand, so unlike `Add`/`Sub` they cost zero constraints: every output
+/// bit is either an existing input bit or the constant `false`.
+pub trait RotateAndShift
+where
+    Self: std::marker::Sized,
+{
+    /// Logical left shift by a constant amount, filling the vacated low bits with `false`.
+    #[must_use]
+    fn shl(&self, by: usize) -> Self;
+
+    /// Arithmetic right shift by a constant amount, sign-extending the vacated high
+    /// bits with the operand's sign bit.
+    #[must_use]
+    fn shr(&self, by: usize) -> Self;
+
+    /// Rotate the bits left by a constant amount.
+    #[must_use]
+    fn rotate_left(&self, by: usize) -> Self;
+
+    /// Rotate the bits right by a constant amount.
+    #[must_use]
+    fn rotate_right(&self, by: usize) -> Self;
+}
+
+macro_rules! shift_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl RotateAndShift for $gadget {
+            fn shl(&self, by: usize) -> Self {
+                assert!(by < <$gadget as Int>::SIZE);
+
+                let bits = (0..<$gadget as Int>::SIZE)
+                    .map(|i| if i < by { Boolean::constant(false) } else { self.bits[i - by].clone() })
+                    .collect();
+
+                Self {
+                    bits,
+                    value: self.value.map(|v| v << by),
+                }
+            }
+
+            fn shr(&self, by: usize) -> Self {
+                assert!(by < <$gadget as Int>::SIZE);
+
+                // The sign bit is the existing top `Boolean`; reuse it to
+                // fill the vacated high bits so the sign is preserved.
+                let sign_bit = self.bits[<$gadget as Int>::SIZE - 1].clone();
+
+                let bits = (0..<$gadget as Int>::SIZE)
+                    .map(|i| {
+                        if i + by < <$gadget as Int>::SIZE {
+                            self.bits[i + by].clone()
+                        } else {
+                            sign_bit.clone()
+                        }
+                    })
+                    .collect();
+
+                Self {
+                    bits,
+                    // `>>` on a signed integer type is already an arithmetic shift
+                    value: self.value.map(|v| v >> by),
+                }
+            }
+
+            fn rotate_left(&self, by: usize) -> Self {
+                let by = by % <$gadget as Int>::SIZE;
+                let size = <$gadget as Int>::SIZE;
+
+                let bits = (0..size).map(|i| self.bits[(i + size - by) % size].clone()).collect();
+
+                Self {
+                    bits,
+                    value: self.value.map(|v| v.rotate_left(by as u32)),
+                }
+            }
+
+            fn rotate_right(&self, by: usize) -> Self {
+                let by = by % <$gadget as Int>::SIZE;
+                let size = <$gadget as Int>::SIZE;
+
+                let bits = (0..size).map(|i| self.bits[(i + by) % size].clone()).collect();
+
+                Self {
+                    bits,
+                    value: self.value.map(|v| v.rotate_right(by as u32)),
+                }
+            }
+        }
+    )*)
+}
+
+shift_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shl_fills_low_bits_with_false() {
+        let a = Int8::constant(0b0000_1111u8 as i8);
+        assert_eq!(a.shl(4).value, Some(0b1111_0000u8 as i8));
+    }
+
+    #[test]
+    fn shr_sign_extends_negative_values() {
+        let a = Int8::constant(-16i8);
+        assert_eq!(a.shr(2).value, Some(-4i8));
+    }
+
+    #[test]
+    fn shr_sign_extends_positive_values() {
+        let a = Int8::constant(0b0100_0000u8 as i8);
+        assert_eq!(a.shr(2).value, Some(0b0001_0000u8 as i8));
+    }
+
+    #[test]
+    fn rotate_left_and_right_are_inverses() {
+        let a = Int8::constant(0b1011_0010u8 as i8);
+        assert_eq!(a.rotate_left(3).rotate_right(3).value, a.value);
+        assert_eq!(a.rotate_left(3).value, Some(a.value.unwrap().rotate_left(3)));
+    }
+
+    #[test]
+    fn rotate_by_size_is_identity() {
+        let a = Int8::constant(0b1011_0010u8 as i8);
+        assert_eq!(a.rotate_left(<Int8 as Int>::SIZE).value, a.value);
+        assert_eq!(a.rotate_right(<Int8 as Int>::SIZE).value, a.value);
+    }
+}
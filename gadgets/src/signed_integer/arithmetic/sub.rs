@@ -0,0 +1,234 @@
+use super::multieq::MultiEq;
+use crate::{errors::IntegerError, Int, Int128, Int16, Int32, Int64, Int8};
+use snarkos_models::{
+    curves::{fp_parameters::FpParameters, PrimeField},
+    gadgets::{
+        r1cs::{Assignment, ConstraintSystem, LinearCombination},
+        utilities::{
+            alloc::AllocGadget,
+            boolean::{AllocatedBit, Boolean},
+        },
+    },
+};
+
+/// Modular subtraction for a signed integer gadget
+///
+/// See [`MultiEq`] for why this takes a `MultiEq` instead of a bare `ConstraintSystem`.
+pub trait Sub<Rhs = Self>
+where
+    Self: std::marker::Sized,
+{
+    #[must_use]
+    fn sub<F: PrimeField, CS: ConstraintSystem<F>>(&self, cs: &mut MultiEq<F, CS>, other: &Self) -> Result<Self, IntegerError>;
+}
+
+macro_rules! sub_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl Sub for $gadget {
+            fn sub<F: PrimeField, CS: ConstraintSystem<F>>(&self, cs: &mut MultiEq<F, CS>, other: &Self) -> Result<Self, IntegerError> {
+                // Make some arbitrary bounds for ourselves to avoid overflows
+                // in the scalar field
+                assert!(F::Params::MODULUS_BITS >= 128);
+
+                // `self - other` is computed as the two's-complement identity
+                // `self + (~other) + 1`, which on the raw (unsigned) bit
+                // patterns evaluates to `self_raw - other_raw + 2 ^ SIZE`, not
+                // a plain difference. Bound the sum for the true unsigned
+                // range of that quantity, one bit wider than `SIZE` to leave
+                // room for the `2 ^ SIZE` offset.
+                let mut max_value = 2i128 * (2i128.pow(<$gadget as Int>::SIZE as u32) - 1i128) + 1i128;
+
+                // The two's-complement identity operates on the raw unsigned
+                // bit pattern of each operand (e.g. `-1i8` contributes `255`,
+                // not `-1`), so mask each value down to its raw `SIZE`-bit
+                // representation before combining them.
+                let raw_mask = 2i128.pow(<$gadget as Int>::SIZE as u32) - 1i128;
+
+                // Keep track of the resulting value, offset by `2 ^ SIZE` to
+                // match the value the identity's linear combination produces
+                let mut result_value = self
+                    .value
+                    .clone()
+                    .map(|v| (i128::from(v) & raw_mask) + 2i128.pow(<$gadget as Int>::SIZE as u32));
+
+                // This is a linear combination that we will enforce to equal the result bits
+                let mut lc = LinearCombination::zero();
+
+                let mut all_constants = true;
+
+                // Accumulate the value
+                match other.value {
+                    Some(val) => {
+                        result_value.as_mut().map(|v| *v -= i128::from(val) & raw_mask);
+                    }
+                    None => {
+                        // If any of the operands have unknown value, we won't
+                        // know the value of the result
+                        result_value = None;
+                    }
+                }
+
+                // Iterate over each bit_gadget of self and add each bit to
+                // the linear combination
+                let mut coeff = F::one();
+                for bit in &self.bits {
+                    match *bit {
+                        Boolean::Is(ref bit) => {
+                            all_constants = false;
+
+                            // Add the coeff * bit_gadget
+                            lc = lc + (coeff, bit.get_variable());
+                        }
+                        Boolean::Not(ref bit) => {
+                            all_constants = false;
+
+                            // Add coeff * (1 - bit_gadget) = coeff * ONE - coeff * bit_gadget
+                            lc = lc + (coeff, CS::one()) - (coeff, bit.get_variable());
+                        }
+                        Boolean::Constant(bit) => {
+                            if bit {
+                                lc = lc + (coeff, CS::one());
+                            }
+                        }
+                    }
+
+                    coeff.double_in_place();
+                }
+
+                // Iterate over each bit_gadget of other, negated via two's
+                // complement (flip `Is`/`Not`, complement `Constant`), and
+                // add each bit to the linear combination
+                let mut coeff = F::one();
+                for bit in &other.bits {
+                    match *bit {
+                        Boolean::Is(ref bit) => {
+                            all_constants = false;
+
+                            // !bit_gadget = coeff * ONE - coeff * bit_gadget
+                            lc = lc + (coeff, CS::one()) - (coeff, bit.get_variable());
+                        }
+                        Boolean::Not(ref bit) => {
+                            all_constants = false;
+
+                            // !(1 - bit_gadget) = coeff * bit_gadget
+                            lc = lc + (coeff, bit.get_variable());
+                        }
+                        Boolean::Constant(bit) => {
+                            if !bit {
+                                lc = lc + (coeff, CS::one());
+                            }
+                        }
+                    }
+
+                    coeff.double_in_place();
+                }
+
+                // Add the constant `1` from the two's-complement identity
+                // `a - b = a + (~b) + 1`
+                lc = lc + (F::one(), CS::one());
+
+                // The value of the actual result is modulo 2 ^ $size
+                let modular_value = result_value.map(|v| v as <$gadget as Int>::IntegerType);
+
+                if all_constants && modular_value.is_some() {
+                    // We can just return a constant, rather than
+                    // unpacking the result into allocated bits.
+
+                    return Ok(Self::constant(modular_value.unwrap()));
+                }
+
+                // Storage area for the resulting bits
+                let mut result_bits = vec![];
+
+                // This linear combination tracks the result bits on the
+                // opposite side of the balance equation from `lc`, so the
+                // equality can be routed through `MultiEq` instead of a
+                // single "lc == 0" constraint
+                let mut rhs = LinearCombination::zero();
+
+                // Allocate each bit_gadget of the result
+                let mut coeff = F::one();
+                let mut i = 0;
+                while max_value != 0 {
+                    // Allocate the bit_gadget
+                    let b = AllocatedBit::alloc(cs.ns(|| format!("result bit_gadget {}", i)), || {
+                        result_value.map(|v| (v >> i) & 1 == 1).get()
+                    })?;
+
+                    // Add this bit_gadget to the other side of the balance equation
+                    rhs = rhs + (coeff, b.get_variable());
+
+                    result_bits.push(b.into());
+
+                    max_value >>= 1;
+                    i += 1;
+                    coeff.double_in_place();
+                }
+
+                // Enforce that the two sides of the balance equation are equal,
+                // batching the constraint with any other additions sharing this
+                // `MultiEq` instead of spending a full field-level constraint
+                cs.enforce_equal(i, &lc, &rhs);
+
+                // Discard carry bits we don't care about
+                result_bits.truncate(<$gadget as Int>::SIZE);
+
+                Ok(Self {
+                    bits: result_bits,
+                    value: modular_value,
+                })
+            }
+        }
+    )*)
+}
+
+sub_int_impl!(Int8 Int16 Int32 Int64 Int128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkos_curves::edwards_bls12::Fq;
+    use snarkos_models::gadgets::r1cs::TestConstraintSystem;
+
+    #[test]
+    fn sub_satisfies_constraints() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(5i8)).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(3i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let c = a.sub(&mut multi_eq, &b).unwrap();
+        drop(multi_eq);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(c.value, Some(2i8));
+    }
+
+    #[test]
+    fn sub_wraps_on_underflow() {
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let a = Int8::alloc(cs.ns(|| "a"), || Ok(i8::min_value())).unwrap();
+        let b = Int8::alloc(cs.ns(|| "b"), || Ok(1i8)).unwrap();
+
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let c = a.sub(&mut multi_eq, &b).unwrap();
+        drop(multi_eq);
+
+        assert!(cs.is_satisfied());
+        assert_eq!(c.value, Some(i8::max_value()));
+    }
+
+    #[test]
+    fn sub_of_constants_is_constant() {
+        let a = Int8::constant(5);
+        let b = Int8::constant(3);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let mut multi_eq = MultiEq::new(&mut cs);
+        let c = a.sub(&mut multi_eq, &b).unwrap();
+        drop(multi_eq);
+
+        assert_eq!(c.value, Some(2i8));
+        assert_eq!(cs.num_constraints(), 0);
+    }
+}